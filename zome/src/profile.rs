@@ -1,7 +1,7 @@
 use crate::utils;
-use hdk::prelude::holo_hash::AgentPubKeyB64;
+use hdk::prelude::holo_hash::{AgentPubKeyB64, EntryHashB64};
 use hdk::prelude::*;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::convert::{TryFrom, TryInto};
 
 #[hdk_entry(id = "profile", visibility = "public")]
@@ -9,9 +9,33 @@ use std::convert::{TryFrom, TryInto};
 pub struct Profile {
     pub nickname: String,
     pub fields: BTreeMap<String, String>,
+    /// Hash of the `Profile` entry this one supersedes, if any. `None` marks
+    /// the genesis entry of an agent's profile chain.
+    pub previous_hash: Option<EntryHash>,
 }
 
-// Used as a return type of all functions
+/// A capability token allowing `grantee` to call `update_profile` on
+/// `owner`'s profile, but only for mutations confined to `allowed_fields`
+/// (never `nickname`), and only until `expiry` if set.
+#[hdk_entry(id = "profile_edit_grant", visibility = "public")]
+#[derive(Clone)]
+pub struct ProfileEditGrant {
+    pub owner: AgentPubKeyB64,
+    pub grantee: AgentPubKeyB64,
+    pub allowed_fields: Vec<String>,
+    pub expiry: Option<Timestamp>,
+}
+
+// Used as a return type of all functions.
+//
+// `agent_pub_key` is always reconstructed from a genuine `AgentPubKey` (the
+// calling agent, an input parameter, an element's header author, or an
+// `AgentPubKeyB64` encoded in a link tag) and never from a link base/target
+// hash, so it always carries the `uhCA` agent prefix. See the
+// `agent_link_base_is_deterministic_and_not_the_agents_real_address` test
+// below and `agent_link_base`'s doc comment
+// for why that distinction matters even though it carries no base/target
+// hash itself.
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct AgentProfile {
     pub agent_pub_key: AgentPubKeyB64,
@@ -21,6 +45,11 @@ pub struct AgentProfile {
 pub fn create_profile(profile: Profile) -> ExternResult<AgentProfile> {
     let agent_info = agent_info()?;
 
+    let profile = Profile {
+        previous_hash: None,
+        ..profile
+    };
+
     create_entry(&profile.clone())?;
 
     let profile_hash = hash_entry(&profile.clone())?;
@@ -29,15 +58,13 @@ pub fn create_profile(profile: Profile) -> ExternResult<AgentProfile> {
 
     path.ensure()?;
 
-    let agent_address: AnyDhtHash = agent_info.agent_initial_pubkey.clone().into();
-
     create_link(
         path.hash()?,
         profile_hash.clone(),
         link_tag(profile.nickname.as_str().clone())?,
     )?;
     create_link(
-        agent_address.into(),
+        agent_link_base(agent_info.agent_initial_pubkey.clone()),
         profile_hash.clone(),
         link_tag("profile")?,
     )?;
@@ -50,6 +77,234 @@ pub fn create_profile(profile: Profile) -> ExternResult<AgentProfile> {
     Ok(agent_profile)
 }
 
+/// Updates a profile. The caller is either the profile's owner, or a
+/// grantee holding an active [`ProfileEditGrant`] from `grant_profile_edit`
+/// that covers every field this call actually changes.
+pub fn update_profile(
+    previous_profile_hash: EntryHash,
+    new_profile: Profile,
+) -> ExternResult<AgentProfile> {
+    let agent_info = agent_info()?;
+    let author = agent_info.agent_initial_pubkey;
+
+    let previous_element = get(previous_profile_hash.clone(), GetOptions::default())?.ok_or_else(|| {
+        crate::err("previous_profile_hash does not reference an existing profile entry")
+    })?;
+    let previous_profile: Profile = utils::try_from_element(previous_element)?;
+    let owner = resolve_profile_owner(previous_profile_hash.clone(), &previous_profile)?;
+
+    let (tip_hash, _) = latest_profile_for_agent(owner.clone())?
+        .ok_or_else(|| crate::err("Cannot update a profile that does not exist yet"))?;
+
+    if tip_hash != previous_profile_hash {
+        return Err(crate::err(
+            "previous_profile_hash does not match the current tip of the profile chain",
+        ));
+    }
+
+    if let Err(reason) =
+        check_edit_authorization(&owner, &author, &previous_profile, &new_profile, sys_time()?)?
+    {
+        return Err(crate::err(&reason));
+    }
+
+    let profile = Profile {
+        previous_hash: Some(previous_profile_hash.clone()),
+        ..new_profile
+    };
+
+    create_entry(&profile.clone())?;
+
+    let profile_hash = hash_entry(&profile.clone())?;
+
+    let previous_path = prefix_path(previous_profile.nickname.clone());
+    let previous_path_links = get_links(
+        previous_path.hash()?,
+        Some(link_tag(previous_profile.nickname.as_str())?),
+    )?;
+
+    for link in previous_path_links {
+        if link.target == previous_profile_hash {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+
+    let path = prefix_path(profile.nickname.clone());
+
+    path.ensure()?;
+
+    create_link(
+        path.hash()?,
+        profile_hash.clone(),
+        link_tag(profile.nickname.as_str().clone())?,
+    )?;
+    create_link(
+        previous_profile_hash,
+        profile_hash,
+        link_tag("profile_update")?,
+    )?;
+
+    Ok(AgentProfile {
+        agent_pub_key: AgentPubKeyB64::from(owner),
+        profile,
+    })
+}
+
+/// Grants `grantee` the capability to call `update_profile` on the calling
+/// agent's profile, restricted to `allowed_fields` (never `nickname`) and
+/// optionally expiring at `expiry`.
+pub fn grant_profile_edit(
+    grantee: AgentPubKeyB64,
+    allowed_fields: Vec<String>,
+    expiry: Option<Timestamp>,
+) -> ExternResult<()> {
+    let agent_info = agent_info()?;
+
+    let grant = ProfileEditGrant {
+        owner: AgentPubKeyB64::from(agent_info.agent_initial_pubkey.clone()),
+        grantee: grantee.clone(),
+        allowed_fields,
+        expiry,
+    };
+
+    create_entry(&grant)?;
+
+    let grant_hash = hash_entry(&grant)?;
+
+    create_link(
+        agent_link_base(agent_info.agent_initial_pubkey),
+        grant_hash,
+        grant_link_tag(&grantee)?,
+    )?;
+
+    Ok(())
+}
+
+/// Revokes every capability the calling agent has granted to `grantee`.
+pub fn revoke_profile_edit(grantee: AgentPubKeyB64) -> ExternResult<()> {
+    let agent_info = agent_info()?;
+
+    let links = get_links(
+        agent_link_base(agent_info.agent_initial_pubkey),
+        Some(grant_link_tag(&grantee)?),
+    )?;
+
+    for link in links {
+        delete_link(link.create_link_hash)?;
+    }
+
+    Ok(())
+}
+
+pub fn get_profile_history(agent_pub_key: AgentPubKeyB64) -> ExternResult<Vec<AgentProfile>> {
+    let agent_pub_key = AgentPubKey::from(agent_pub_key.clone());
+
+    let links = get_links(
+        agent_link_base(agent_pub_key),
+        Some(link_tag("profile")?),
+    )?;
+
+    if links.len() == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut history = Vec::new();
+    let mut current_hash = links[0].target.clone();
+
+    loop {
+        let profile: Profile = utils::try_get_and_convert(current_hash.clone())?;
+        let element = get(current_hash.clone(), GetOptions::default())?
+            .ok_or_else(|| crate::err("Could not find profile entry for its own hash"))?;
+        let agent_pub_key = AgentPubKeyB64::from(element.header().author().clone());
+
+        history.push(AgentProfile {
+            agent_pub_key,
+            profile,
+        });
+
+        let updates = get_links(current_hash.clone(), Some(link_tag("profile_update")?))?;
+
+        if updates.len() == 0 {
+            break;
+        }
+
+        current_hash = updates[0].target.clone();
+    }
+
+    Ok(history)
+}
+
+/// Records the calling agent's trust in `target` on a given `topic`. The
+/// rating is stored as a link from the rater's agent address to `target`'s
+/// agent address (not a specific profile entry, which `target` may later
+/// supersede via `update_profile`), with `target`, `topic` and `weight`
+/// encoded in the link tag so `recommended_profiles` can resolve `target`'s
+/// current profile at read time.
+pub fn rate_agent(target: AgentPubKeyB64, topic: String, weight: f32) -> ExternResult<()> {
+    let agent_info = agent_info()?;
+
+    let target_pub_key = AgentPubKey::from(target.clone());
+    latest_profile_for_agent(target_pub_key.clone())?
+        .ok_or_else(|| crate::err("Cannot rate an agent that does not have a profile"))?;
+
+    create_link(
+        agent_link_base(agent_info.agent_initial_pubkey),
+        agent_link_base(target_pub_key),
+        trust_link_tag(&target, topic.as_str(), weight)?,
+    )?;
+
+    Ok(())
+}
+
+/// Resolves the profiles the calling agent has rated at or above `threshold`
+/// (default `0.0`), optionally restricted to a single `topic`, deduped by
+/// target.
+pub fn recommended_profiles(
+    topic: Option<String>,
+    threshold: f32,
+) -> ExternResult<Vec<AgentProfile>> {
+    let agent_info = agent_info()?;
+
+    let links = get_links(agent_link_base(agent_info.agent_initial_pubkey), None)?;
+
+    let mut seen = BTreeSet::new();
+    let mut targets = Vec::new();
+
+    for link in links {
+        let tag = match TrustLinkTag::try_from(link.tag.clone()) {
+            Ok(tag) => tag,
+            Err(_) => continue,
+        };
+
+        if let Some(topic) = &topic {
+            if &tag.topic != topic {
+                continue;
+            }
+        }
+
+        if tag.weight < threshold {
+            continue;
+        }
+
+        if seen.insert(tag.target.clone()) {
+            targets.push(tag.target);
+        }
+    }
+
+    let mut agent_profiles = Vec::new();
+
+    for target in targets {
+        if let Some((_, profile)) = latest_profile_for_agent(AgentPubKey::from(target.clone()))? {
+            agent_profiles.push(AgentProfile {
+                agent_pub_key: target,
+                profile,
+            });
+        }
+    }
+
+    Ok(agent_profiles)
+}
+
 pub fn search_profiles(nickname_prefix: String) -> ExternResult<Vec<AgentProfile>> {
     if nickname_prefix.len() < 3 {
         return Err(crate::err(
@@ -62,6 +317,66 @@ pub fn search_profiles(nickname_prefix: String) -> ExternResult<Vec<AgentProfile
     get_agent_profiles_for_path(prefix_path.hash()?)
 }
 
+/// Tags the calling agent with each of `tags`, creating a link from
+/// `tags.<tag>` to the agent's address (not a specific profile entry, which
+/// the agent may later supersede via `update_profile`) for each one, with
+/// the agent's real `AgentPubKey` encoded in the link tag so
+/// `search_profiles_by_tag` can resolve their current profile at read time.
+pub fn add_profile_tags(tags: Vec<String>) -> ExternResult<()> {
+    let agent_info = agent_info()?;
+    let agent_pub_key_b64 = AgentPubKeyB64::from(agent_info.agent_initial_pubkey.clone());
+
+    latest_profile_for_agent(agent_info.agent_initial_pubkey.clone())?
+        .ok_or_else(|| crate::err("Cannot tag a profile that does not exist yet"))?;
+
+    for tag in tags {
+        let normalized = normalize_tag(tag)?;
+        let path = tag_path(&normalized);
+
+        path.ensure()?;
+
+        create_link(
+            path.hash()?,
+            agent_link_base(agent_info.agent_initial_pubkey.clone()),
+            profile_tag_link_tag(&agent_pub_key_b64)?,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Removes each of `tags` from the calling agent's current profile.
+pub fn remove_profile_tags(tags: Vec<String>) -> ExternResult<()> {
+    let agent_info = agent_info()?;
+
+    latest_profile_for_agent(agent_info.agent_initial_pubkey.clone())?
+        .ok_or_else(|| crate::err("Cannot untag a profile that does not exist yet"))?;
+
+    let own_link_base = agent_link_base(agent_info.agent_initial_pubkey);
+
+    for tag in tags {
+        let normalized = normalize_tag(tag)?;
+        let path = tag_path(&normalized);
+
+        let links = get_links(path.hash()?, None)?;
+
+        for link in links {
+            if link.target == own_link_base {
+                delete_link(link.create_link_hash)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn search_profiles_by_tag(tag: String) -> ExternResult<Vec<AgentProfile>> {
+    let normalized = normalize_tag(tag)?;
+    let path = tag_path(&normalized);
+
+    get_tagged_agent_profiles(path.hash()?)
+}
+
 pub fn get_all_profiles() -> ExternResult<Vec<AgentProfile>> {
     let path = Path::from("all_profiles");
 
@@ -83,24 +398,23 @@ pub fn get_agent_profile(
 ) -> ExternResult<Option<AgentProfile>> {
     let agent_pub_key = AgentPubKey::from(wrapped_agent_pub_key.clone());
 
-    let agent_address: AnyDhtHash = agent_pub_key.into();
-
-    let links = get_links(agent_address.into(), Some(link_tag("profile")?))?;
-
-    if links.len() == 0 {
-        return Ok(None);
-    }
-
-    let link = links[0].clone();
-
-    let profile: Profile = utils::try_get_and_convert(link.target)?;
+    let tip = match latest_profile_for_agent(agent_pub_key)? {
+        Some((_, profile)) => profile,
+        None => return Ok(None),
+    };
 
-    let agent_profile = AgentProfile {
+    Ok(Some(AgentProfile {
         agent_pub_key: wrapped_agent_pub_key,
-        profile,
-    };
+        profile: tip,
+    }))
+}
+
+/// Returns the entry hash of `agent`'s current profile (the tip of their
+/// update chain), or `None` if they have no profile yet.
+pub fn get_agent_profile_hash(agent: AgentPubKeyB64) -> ExternResult<Option<EntryHash>> {
+    let agent_pub_key = AgentPubKey::from(agent);
 
-    Ok(Some(agent_profile))
+    Ok(latest_profile_for_agent(agent_pub_key)?.map(|(hash, _)| hash))
 }
 
 pub fn get_agents_profile(
@@ -112,8 +426,7 @@ pub fn get_agents_profile(
         .into_iter()
         .map(|agent_pub_key_b64| {
             let agent_pub_key = AgentPubKey::from(agent_pub_key_b64.clone());
-            let agent_address: AnyDhtHash = agent_pub_key.into();
-            GetLinksInput::new(agent_address.into(), link_tag.clone())
+            GetLinksInput::new(agent_link_base(agent_pub_key), link_tag.clone())
         })
         .collect();
 
@@ -138,6 +451,290 @@ pub fn get_agents_profile(
 
 /** Private helpers */
 
+/// Computes the DHT address used as the base for every link anchored on an
+/// agent (`"profile"`, trust ratings). This is the same
+/// `AgentPubKey -> AnyDhtHash -> EntryHash` conversion every call site used
+/// to write out by hand; centralizing it here doesn't change the address it
+/// produces, only guarantees every caller derives it the same way instead of
+/// risking divergent copies of the conversion drifting apart over time. The
+/// resulting hash carries the `uhCE` entry prefix, not the agent's real
+/// `uhCA` prefix, which is precisely why no caller may reconstruct
+/// `AgentProfile.agent_pub_key` from this address — it must always come
+/// from a header author or an input parameter (see the
+/// `agent_link_base_is_deterministic_and_not_the_agents_real_address` test
+/// below).
+fn agent_link_base(agent_pub_key: AgentPubKey) -> EntryHash {
+    let any_dht_hash: AnyDhtHash = agent_pub_key.into();
+    any_dht_hash.into()
+}
+
+/// Walks an agent's profile update chain to its tip, following `"profile_update"`
+/// links from the genesis entry reachable via the `"profile"` link.
+fn latest_profile_for_agent(agent_pub_key: AgentPubKey) -> ExternResult<Option<(EntryHash, Profile)>> {
+    let links = get_links(agent_link_base(agent_pub_key), Some(link_tag("profile")?))?;
+
+    if links.len() == 0 {
+        return Ok(None);
+    }
+
+    let mut current_hash = links[0].target.clone();
+
+    loop {
+        let updates = get_links(current_hash.clone(), Some(link_tag("profile_update")?))?;
+
+        if updates.len() == 0 {
+            break;
+        }
+
+        current_hash = updates[0].target.clone();
+    }
+
+    let profile: Profile = utils::try_get_and_convert(current_hash.clone())?;
+
+    Ok(Some((current_hash, profile)))
+}
+
+/// Validation for a `Profile` update entry: rejects the write unless
+/// `previous_hash` points at an entry that is actually the current tip of
+/// its owner's chain (the same way a previous-hash comparison catches a
+/// fork when walking heights downward), and unless `author` is either that
+/// owner or the holder of an active capability grant covering the fields
+/// this entry actually changes. `timestamp` is the header timestamp of the
+/// entry under validation (never live `sys_time()`), so every validator
+/// reaches the same verdict regardless of when it happens to validate.
+pub(crate) fn validate_profile_update(
+    profile: &Profile,
+    author: &AgentPubKey,
+    timestamp: Timestamp,
+) -> ExternResult<ValidateCallbackResult> {
+    let previous_hash = match &profile.previous_hash {
+        None => return Ok(ValidateCallbackResult::Valid),
+        Some(previous_hash) => previous_hash.clone(),
+    };
+
+    let previous_element = match get(previous_hash.clone(), GetOptions::default())? {
+        None => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "previous_hash does not reference an existing entry".into(),
+            ))
+        }
+        Some(element) => element,
+    };
+    let previous_profile: Profile = utils::try_from_element(previous_element)?;
+    let owner = resolve_profile_owner(previous_hash.clone(), &previous_profile)?;
+
+    let tip = match latest_profile_for_agent(owner.clone())? {
+        None => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "previous_hash set but its owner has no existing profile".into(),
+            ))
+        }
+        Some((tip_hash, _)) => tip_hash,
+    };
+
+    if tip != previous_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "previous_hash does not match the latest entry for this agent; update is forked or stale".into(),
+        ));
+    }
+
+    match check_edit_authorization(&owner, author, &previous_profile, profile, timestamp)? {
+        Ok(()) => Ok(ValidateCallbackResult::Valid),
+        Err(reason) => Ok(ValidateCallbackResult::Invalid(reason)),
+    }
+}
+
+/// Resolves the true owner of a profile chain: the author of its genesis
+/// entry (the one with `previous_hash == None`), found by walking
+/// `previous_hash` back from `profile_hash`/`profile`. A delegated edit's
+/// tip entry is authored by the grantee, not the owner, so the tip's
+/// header author must never be used as the owner.
+fn resolve_profile_owner(profile_hash: EntryHash, profile: &Profile) -> ExternResult<AgentPubKey> {
+    match &profile.previous_hash {
+        None => {
+            let element = get(profile_hash, GetOptions::default())?
+                .ok_or_else(|| crate::err("Could not find profile entry while resolving owner"))?;
+            Ok(element.header().author().clone())
+        }
+        Some(previous_hash) => resolve_profile_owner_from_hash(previous_hash.clone()),
+    }
+}
+
+fn resolve_profile_owner_from_hash(profile_hash: EntryHash) -> ExternResult<AgentPubKey> {
+    let element = get(profile_hash, GetOptions::default())?
+        .ok_or_else(|| crate::err("Could not find profile entry while resolving owner"))?;
+    let profile: Profile = utils::try_from_element(element.clone())?;
+
+    match profile.previous_hash {
+        None => Ok(element.header().author().clone()),
+        Some(previous_hash) => resolve_profile_owner_from_hash(previous_hash),
+    }
+}
+
+/// Checks whether `author` may write `new_profile` over `old_profile` on
+/// `owner`'s chain: either `author == owner`, or `author` holds an active,
+/// unexpired [`ProfileEditGrant`] from `owner` whose `allowed_fields` cover
+/// every field this write actually changes (never `nickname`, which a grant
+/// can never cover). Revoked or expired grants are treated as absent.
+fn check_edit_authorization(
+    owner: &AgentPubKey,
+    author: &AgentPubKey,
+    old_profile: &Profile,
+    new_profile: &Profile,
+    now: Timestamp,
+) -> ExternResult<Result<(), String>> {
+    if author == owner {
+        return Ok(Ok(()));
+    }
+
+    let grant = match active_grant_for(owner, author, now)? {
+        None => {
+            return Ok(Err(
+                "author is neither the profile owner nor the holder of an active capability grant"
+                    .into(),
+            ))
+        }
+        Some(grant) => grant,
+    };
+
+    let mutated_fields = mutated_field_names(old_profile, new_profile);
+
+    if mutated_fields.iter().any(|field| field == "nickname") {
+        return Ok(Err("a delegated edit cannot change nickname".into()));
+    }
+
+    if mutated_fields
+        .iter()
+        .any(|field| !grant.allowed_fields.contains(field))
+    {
+        return Ok(Err(
+            "delegated edit touches a field outside the granted allowed_fields".into(),
+        ));
+    }
+
+    Ok(Ok(()))
+}
+
+/// Names of the fields that differ between `old_profile` and `new_profile`
+/// (`"nickname"` plus any changed, added, or removed key of `fields`).
+fn mutated_field_names(old_profile: &Profile, new_profile: &Profile) -> Vec<String> {
+    let mut mutated = Vec::new();
+
+    if old_profile.nickname != new_profile.nickname {
+        mutated.push("nickname".to_string());
+    }
+
+    let mut keys: BTreeSet<&String> = old_profile.fields.keys().collect();
+    keys.extend(new_profile.fields.keys());
+
+    for key in keys {
+        if old_profile.fields.get(key) != new_profile.fields.get(key) {
+            mutated.push(key.clone());
+        }
+    }
+
+    mutated
+}
+
+/// Looks up a grant `owner` has issued to `grantee` that is still active as
+/// of `now`. `now` is supplied by the caller (live `sys_time()` when
+/// authoring, the header timestamp under validation when validating) so
+/// this check is deterministic across validators.
+///
+/// `owner`'s link base is a public address: anyone can author a `CreateLink`
+/// there, and anyone can author a `ProfileEditGrant` entry whose `owner`
+/// field merely *claims* to be `owner`. Neither is proof of anything, so
+/// this only trusts a grant once the link that points at it was itself
+/// authored by `owner` (a header's author can't be forged) and the grant
+/// entry's own `owner`/`grantee` fields agree with what we looked up.
+fn active_grant_for(
+    owner: &AgentPubKey,
+    grantee: &AgentPubKey,
+    now: Timestamp,
+) -> ExternResult<Option<ProfileEditGrant>> {
+    let grantee_b64 = AgentPubKeyB64::from(grantee.clone());
+    let owner_b64 = AgentPubKeyB64::from(owner.clone());
+
+    let links = get_links(
+        agent_link_base(owner.clone()),
+        Some(grant_link_tag(&grantee_b64)?),
+    )?;
+
+    for link in links {
+        let link_header = get(link.create_link_hash.clone(), GetOptions::default())?
+            .ok_or_else(|| crate::err("Could not find the CreateLink header for a grant link"))?;
+
+        if link_header.header().author() != owner {
+            continue;
+        }
+
+        let grant: ProfileEditGrant = utils::try_get_and_convert(link.target)?;
+
+        if grant.owner != owner_b64 || grant.grantee != grantee_b64 {
+            continue;
+        }
+
+        if is_grant_active(&grant, now) {
+            return Ok(Some(grant));
+        }
+    }
+
+    Ok(None)
+}
+
+fn is_grant_active(grant: &ProfileEditGrant, now: Timestamp) -> bool {
+    match grant.expiry {
+        None => true,
+        Some(expiry) => now < expiry,
+    }
+}
+
+const MAX_TAG_LEN: usize = 64;
+
+/// Normalizes a tag the same way for every caller, so adding, removing and
+/// searching a tag always derive its `tags.<tag>` path from the identical
+/// string. (The `LinkTag` on each tag link carries the tagged agent's key,
+/// not the tag.)
+fn normalize_tag(tag: String) -> ExternResult<String> {
+    let normalized = tag.trim().to_lowercase();
+
+    validate_tag(&normalized).map_err(|reason| crate::err(&reason))?;
+
+    Ok(normalized)
+}
+
+fn tag_path(normalized_tag: &str) -> Path {
+    Path::from(format!("tags.{}", normalized_tag))
+}
+
+/// Shared tag normalization rule for both the `add_profile_tags` extern and
+/// the link validation callback, so the tag index can never drift out of
+/// sync with what validation accepts.
+fn validate_tag(tag: &str) -> Result<(), String> {
+    if tag.is_empty() {
+        return Err("Tag cannot be empty".into());
+    }
+
+    if tag.len() > MAX_TAG_LEN {
+        return Err(format!("Tag cannot be longer than {} characters", MAX_TAG_LEN));
+    }
+
+    if tag.contains('.') {
+        return Err("Tag cannot contain '.'".into());
+    }
+
+    Ok(())
+}
+
+/// Validation for a `"tags.<tag>"` path link: rejects tags that would fail
+/// `validate_tag`'s normalization rule and so corrupt the path segment.
+pub(crate) fn validate_tag_link(tag: &str) -> ExternResult<ValidateCallbackResult> {
+    match validate_tag(tag) {
+        Ok(()) => Ok(ValidateCallbackResult::Valid),
+        Err(reason) => Ok(ValidateCallbackResult::Invalid(reason)),
+    }
+}
+
 fn prefix_path(nickname: String) -> Path {
     // conver to lowercase for path for ease of search
     let lower_nickname = nickname.to_lowercase();
@@ -163,22 +760,200 @@ fn get_agent_profiles_for_path(path_hash: EntryHash) -> ExternResult<Vec<AgentPr
         .collect()
 }
 
+/// Builds an `AgentProfile` from a fetched `Profile` element. The element's
+/// own header author is only who wrote *this particular entry*, which is
+/// the grantee for a delegated edit, not the profile's owner — so, like
+/// `update_profile`/`validate_profile_update`, this resolves the owner by
+/// walking the chain to its genesis entry instead.
 fn get_agent_profile_from_element(element: Element) -> ExternResult<AgentProfile> {
-    let author = element.header().author().clone();
-
     let profile: Profile = utils::try_from_element(element)?;
+    let profile_hash = hash_entry(&profile.clone())?;
+    let owner = resolve_profile_owner(profile_hash, &profile)?;
 
     let agent_profile = AgentProfile {
-        agent_pub_key: AgentPubKeyB64::from(author),
+        agent_pub_key: AgentPubKeyB64::from(owner),
         profile,
     };
 
     Ok(agent_profile)
 }
 
+/// Resolves the agents tagged at `path_hash` to their current profiles.
+/// Unlike `get_agent_profiles_for_path`, the links here target an agent
+/// address rather than a profile entry, so each tagged agent's real
+/// `AgentPubKey` is decoded from the link tag and their profile is
+/// re-resolved at read time instead of being fetched directly.
+fn get_tagged_agent_profiles(path_hash: EntryHash) -> ExternResult<Vec<AgentProfile>> {
+    let links = get_links(path_hash, None)?;
+
+    let mut agent_profiles = Vec::new();
+
+    for link in links {
+        let tag = match ProfileTagLinkTag::try_from(link.tag.clone()) {
+            Ok(tag) => tag,
+            Err(_) => continue,
+        };
+
+        if let Some((_, profile)) = latest_profile_for_agent(AgentPubKey::from(tag.agent.clone()))? {
+            agent_profiles.push(AgentProfile {
+                agent_pub_key: tag.agent,
+                profile,
+            });
+        }
+    }
+
+    Ok(agent_profiles)
+}
+
 #[derive(Serialize, Deserialize, Debug, SerializedBytes)]
 struct StringLinkTag(String);
 pub fn link_tag(tag: &str) -> ExternResult<LinkTag> {
     let sb: SerializedBytes = StringLinkTag(tag.into()).try_into()?;
     Ok(LinkTag(sb.bytes().clone()))
 }
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes)]
+struct TrustLinkTag {
+    target: AgentPubKeyB64,
+    topic: String,
+    weight: f32,
+}
+
+impl TryFrom<LinkTag> for TrustLinkTag {
+    type Error = SerializedBytesError;
+
+    fn try_from(tag: LinkTag) -> Result<Self, Self::Error> {
+        let sb = SerializedBytes::from(UnsafeBytes::from(tag.0));
+        TrustLinkTag::try_from(sb)
+    }
+}
+
+fn trust_link_tag(target: &AgentPubKeyB64, topic: &str, weight: f32) -> ExternResult<LinkTag> {
+    let sb: SerializedBytes = TrustLinkTag {
+        target: target.clone(),
+        topic: topic.into(),
+        weight,
+    }
+    .try_into()?;
+    Ok(LinkTag(sb.bytes().clone()))
+}
+
+fn grant_link_tag(grantee: &AgentPubKeyB64) -> ExternResult<LinkTag> {
+    link_tag(format!("profile_edit_grant:{}", grantee).as_str())
+}
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes)]
+struct ProfileTagLinkTag {
+    agent: AgentPubKeyB64,
+}
+
+impl TryFrom<LinkTag> for ProfileTagLinkTag {
+    type Error = SerializedBytesError;
+
+    fn try_from(tag: LinkTag) -> Result<Self, Self::Error> {
+        let sb = SerializedBytes::from(UnsafeBytes::from(tag.0));
+        ProfileTagLinkTag::try_from(sb)
+    }
+}
+
+fn profile_tag_link_tag(agent: &AgentPubKeyB64) -> ExternResult<LinkTag> {
+    let sb: SerializedBytes = ProfileTagLinkTag {
+        agent: agent.clone(),
+    }
+    .try_into()?;
+    Ok(LinkTag(sb.bytes().clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_agent_pub_key(byte: u8) -> AgentPubKey {
+        AgentPubKey::from_raw_36(vec![byte; 36])
+    }
+
+    #[test]
+    fn agent_link_base_is_deterministic_and_not_the_agents_real_address() {
+        let agent_pub_key = test_agent_pub_key(1);
+
+        let link_base_a = agent_link_base(agent_pub_key.clone());
+        let link_base_b = agent_link_base(agent_pub_key.clone());
+
+        // Every caller must derive the same address for the same agent.
+        assert_eq!(link_base_a, link_base_b);
+
+        // `agent_link_base` carries the `uhCE` entry prefix, never the
+        // agent's real `uhCA` prefix, which is precisely why no caller may
+        // reconstruct `AgentProfile.agent_pub_key` from it.
+        let link_base_b64 = EntryHashB64::from(link_base_a);
+        let real_agent_b64 = AgentPubKeyB64::from(agent_pub_key);
+
+        assert!(link_base_b64.to_string().starts_with("uhCE"));
+        assert!(real_agent_b64.to_string().starts_with("uhCA"));
+        assert_ne!(link_base_b64.to_string(), real_agent_b64.to_string());
+    }
+
+    #[test]
+    fn mutated_field_names_detects_nickname_and_field_changes() {
+        let mut old_fields = BTreeMap::new();
+        old_fields.insert("bio".to_string(), "hello".to_string());
+        old_fields.insert("location".to_string(), "here".to_string());
+
+        let old_profile = Profile {
+            nickname: "alice".to_string(),
+            fields: old_fields,
+            previous_hash: None,
+        };
+
+        let mut new_fields = old_profile.fields.clone();
+        new_fields.insert("bio".to_string(), "updated".to_string());
+        new_fields.remove("location");
+        new_fields.insert("website".to_string(), "example.com".to_string());
+
+        let new_profile = Profile {
+            nickname: "alice-renamed".to_string(),
+            fields: new_fields,
+            previous_hash: None,
+        };
+
+        let mut mutated = mutated_field_names(&old_profile, &new_profile);
+        mutated.sort();
+
+        assert_eq!(
+            mutated,
+            vec![
+                "bio".to_string(),
+                "location".to_string(),
+                "nickname".to_string(),
+                "website".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn mutated_field_names_is_empty_for_identical_profiles() {
+        let mut fields = BTreeMap::new();
+        fields.insert("bio".to_string(), "hello".to_string());
+
+        let profile = Profile {
+            nickname: "alice".to_string(),
+            fields,
+            previous_hash: None,
+        };
+
+        assert!(mutated_field_names(&profile, &profile).is_empty());
+    }
+
+    #[test]
+    fn is_grant_active_respects_the_given_timestamp_not_live_sys_time() {
+        let grant = ProfileEditGrant {
+            owner: AgentPubKeyB64::from(test_agent_pub_key(1)),
+            grantee: AgentPubKeyB64::from(test_agent_pub_key(2)),
+            allowed_fields: vec!["bio".to_string()],
+            expiry: Some(Timestamp::from_micros(1_000)),
+        };
+
+        assert!(is_grant_active(&grant, Timestamp::from_micros(500)));
+        assert!(!is_grant_active(&grant, Timestamp::from_micros(1_500)));
+    }
+}